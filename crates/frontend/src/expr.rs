@@ -0,0 +1,100 @@
+use ecow::EcoString;
+
+use crate::lexer::Loc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Binary(BinaryExpr),
+    Unary(UnaryExpr),
+    Grouping(GroupingExpr),
+    Identifier(IdentifierExpr),
+    IntLiteral(IntLiteralExpr),
+    RealLiteral(RealLiteralExpr),
+    Assign(AssignExpr),
+    Logical(LogicalExpr),
+    Call(CallExpr),
+    Get(GetExpr),
+    StringLiteral(StringLiteralExpr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryExpr {
+    pub left: Box<Expr>,
+    pub operator: EcoString,
+    pub right: Box<Expr>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnaryExpr {
+    pub operator: EcoString,
+    pub right: Box<Expr>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupingExpr {
+    pub expr: Box<Expr>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifierExpr {
+    pub name: EcoString,
+    pub loc: Loc,
+    // Filled in by the resolver once it has walked the scope chain, so the
+    // AST carries its own lexical depth instead of relying on a side table.
+    pub resolved_depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntLiteralExpr {
+    pub value: i64,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealLiteralExpr {
+    pub value: f64,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignExpr {
+    pub name: EcoString,
+    pub value: Box<Expr>,
+    pub loc: Loc,
+    pub resolved_depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalExpr {
+    pub left: Box<Expr>,
+    pub operator: EcoString,
+    pub right: Box<Expr>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallExpr {
+    pub callee: Box<Expr>,
+    pub args: Vec<Expr>,
+    pub paren_loc: Loc,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetExpr {
+    pub object: Box<Expr>,
+    pub name: EcoString,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringLiteralExpr {
+    pub value: EcoString,
+    // Fast-path flag: callers that don't care about escapes (e.g. constant
+    // folding on the raw source slice) can skip decoding entirely.
+    pub has_escape: bool,
+    pub loc: Loc,
+}
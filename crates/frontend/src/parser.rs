@@ -1,20 +1,72 @@
-use crate::expr::{BinaryExpr, Expr, GroupingExpr, IdentifierExpr, IntLiteralExpr, RealLiteralExpr, UnaryExpr};
+use ecow::EcoString;
+
+use crate::expr::{
+    AssignExpr, BinaryExpr, CallExpr, Expr, GetExpr, GroupingExpr, IdentifierExpr, IntLiteralExpr,
+    LogicalExpr, RealLiteralExpr, StringLiteralExpr, UnaryExpr,
+};
 use crate::results::ArcResult;
 use crate::lexer::{Loc, Token, TokenKind};
+use crate::stmt::{
+    BlockStmt, ExprStmt, FnStmt, IfStmt, PrintStmt, ReturnStmt, Stmt, StructStmt, VarStmt, WhileStmt,
+};
 
 pub struct Parser<'a> {
     tokens: &'a [Token],
     start_loc: usize,
     current: usize,
-    nodes: Vec<Expr>,
+    nodes: Vec<Stmt>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Assoc {
+    Left,
+    #[allow(dead_code)]
+    Right,
+}
+
+// What the parser was trying to build when an error fired, so the message
+// can point at the right spot instead of a generic "unexpected token".
+#[derive(Debug, Clone, Copy)]
+enum ParseContext {
+    Expression,
+    Grouping { open_loc: Loc },
+    CallArgs { open_loc: Loc },
+    ParamList { open_loc: Loc },
+    StructBody { open_loc: Loc },
+    Block { open_loc: Loc },
+}
+
+impl ParseContext {
+    fn describe(&self) -> String {
+        match self {
+            ParseContext::Expression => "expected expression".into(),
+            ParseContext::Grouping { open_loc } => {
+                format!("unclosed grouping started at {}..{}", open_loc.start, open_loc.end)
+            }
+            ParseContext::CallArgs { open_loc } => {
+                format!("call opened at {}..{}", open_loc.start, open_loc.end)
+            }
+            ParseContext::ParamList { open_loc } => {
+                format!("unclosed parameter list started at {}..{}", open_loc.start, open_loc.end)
+            }
+            ParseContext::StructBody { open_loc } => {
+                format!("unclosed struct body started at {}..{}", open_loc.start, open_loc.end)
+            }
+            ParseContext::Block { open_loc } => {
+                format!("unclosed block started at {}..{}", open_loc.start, open_loc.end)
+            }
+        }
+    }
 }
 
 impl<'a> Parser<'a> {
+    const MAX_ARGS: usize = 255;
+
     pub fn new(tokens: &'a [Token]) -> Self {
         Parser { tokens, start_loc: 0, current: 0, nodes: vec![] }
     }
 
-    pub fn parse(&mut self) -> Result<&Vec<Expr>, Vec<ArcResult>> {
+    pub fn parse(&mut self) -> Result<&Vec<Stmt>, Vec<ArcResult>> {
         let mut errors: Vec<ArcResult> = vec![];
 
         while !self.eof() {
@@ -23,18 +75,18 @@ impl<'a> Parser<'a> {
             while !self.eof() && self.is_at(TokenKind::NewLine) {
                 self.current += 1;
             }
-            
+
             // We could have reached EOF while skipping new lines
             if self.eof() { break }
 
             self.start_loc = self.at().loc.start;
 
-            match self.parse_expr() {
-                Ok(expr) => self.nodes.push(expr),
+            match self.parse_declaration() {
+                Ok(stmt) => self.nodes.push(stmt),
                 Err(e) => { errors.push(e) }
             }
         }
-        
+
         if !errors.is_empty() {
             return Err(errors)
         }
@@ -42,38 +94,323 @@ impl<'a> Parser<'a> {
         Ok(&self.nodes)
 }
 
+    fn parse_declaration(&mut self) -> Result<Stmt, ArcResult> {
+        if self.is_at(TokenKind::Var) || self.is_at(TokenKind::Const) {
+            return self.parse_var_declaration()
+        }
+
+        if self.is_at(TokenKind::Fn) {
+            return self.parse_fn_declaration()
+        }
+
+        if self.is_at(TokenKind::Struct) {
+            return self.parse_struct_declaration()
+        }
+
+        self.parse_statement()
+    }
+
+    fn parse_var_declaration(&mut self) -> Result<Stmt, ArcResult> {
+        let is_const = self.is_at(TokenKind::Const);
+        self.eat()?;
+
+        self.expect(TokenKind::Identifier)?;
+        let name = self.prev().value.clone();
+
+        let initializer = if self.is_at(TokenKind::Equal) {
+            self.eat()?;
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+
+        Ok(Stmt::Var(VarStmt { name, initializer, is_const, loc: self.get_loc() }))
+    }
+
+    fn parse_statement(&mut self) -> Result<Stmt, ArcResult> {
+        if self.is_at(TokenKind::OpenBrace) {
+            return self.parse_block()
+        }
+
+        if self.is_at(TokenKind::If) {
+            return self.parse_if_statement()
+        }
+
+        if self.is_at(TokenKind::While) {
+            return self.parse_while_statement()
+        }
+
+        if self.is_at(TokenKind::For) {
+            return self.parse_for_statement()
+        }
+
+        if self.is_at(TokenKind::Print) {
+            return self.parse_print_statement()
+        }
+
+        if self.is_at(TokenKind::Return) {
+            return self.parse_return_statement()
+        }
+
+        self.parse_expr_statement()
+    }
+
+    fn parse_expr_statement(&mut self) -> Result<Stmt, ArcResult> {
+        let expr = self.parse_expr()?;
+        Ok(Stmt::Expr(ExprStmt { expr, loc: self.get_loc() }))
+    }
+
+    fn parse_block(&mut self) -> Result<Stmt, ArcResult> {
+        self.expect(TokenKind::OpenBrace)?;
+        let open_loc = Loc::new(self.start_loc, self.prev().loc.start);
+        let mut stmts = vec![];
+
+        loop {
+            while !self.eof() && self.is_at(TokenKind::NewLine) {
+                self.current += 1;
+            }
+
+            if self.eof() || self.is_at(TokenKind::CloseBrace) {
+                break
+            }
+
+            stmts.push(self.parse_declaration()?);
+        }
+
+        self.expect_or_resync(
+            TokenKind::CloseBrace,
+            "Missing closing '}' for this block".into(),
+            ParseContext::Block { open_loc },
+        )?;
+
+        Ok(Stmt::Block(BlockStmt { stmts, loc: self.get_loc() }))
+    }
+
+    fn parse_if_statement(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'if'
+        let condition = self.parse_expr()?;
+        let then_branch = Box::new(self.parse_statement()?);
+
+        while !self.eof() && self.is_at(TokenKind::NewLine) {
+            self.current += 1;
+        }
+
+        let else_branch = if self.is_at(TokenKind::Else) {
+            self.eat()?;
+            Some(Box::new(self.parse_statement()?))
+        } else {
+            None
+        };
+
+        Ok(Stmt::If(IfStmt { condition, then_branch, else_branch, loc: self.get_loc() }))
+    }
+
+    fn parse_while_statement(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'while'
+        let condition = self.parse_expr()?;
+        let body = Box::new(self.parse_statement()?);
+
+        Ok(Stmt::While(WhileStmt { condition, body, loc: self.get_loc() }))
+    }
+
+    // Desugared into an equivalent `while` wrapped in a block, rather than
+    // carrying its own runtime representation.
+    fn parse_for_statement(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'for'
+        self.expect(TokenKind::OpenParen)?;
+
+        let initializer = if self.is_at(TokenKind::Semicolon) {
+            None
+        } else if self.is_at(TokenKind::Var) || self.is_at(TokenKind::Const) {
+            Some(self.parse_var_declaration()?)
+        } else {
+            Some(self.parse_expr_statement()?)
+        };
+        self.expect(TokenKind::Semicolon)?;
+
+        let condition = if self.is_at(TokenKind::Semicolon) {
+            None
+        } else {
+            Some(self.parse_expr()?)
+        };
+        self.expect(TokenKind::Semicolon)?;
+
+        let increment = if self.is_at(TokenKind::CloseParen) {
+            None
+        } else {
+            Some(self.parse_expr()?)
+        };
+        self.expect(TokenKind::CloseParen)?;
+
+        let loc = self.get_loc();
+        let mut body = self.parse_statement()?;
+
+        if let Some(increment) = increment {
+            body = Stmt::Block(BlockStmt {
+                stmts: vec![body, Stmt::Expr(ExprStmt { expr: increment, loc })],
+                loc,
+            });
+        }
+
+        let condition = condition.unwrap_or(Expr::Identifier(IdentifierExpr { name: "true".into(), loc, resolved_depth: None }));
+        body = Stmt::While(WhileStmt { condition, body: Box::new(body), loc });
+
+        if let Some(initializer) = initializer {
+            body = Stmt::Block(BlockStmt { stmts: vec![initializer, body], loc });
+        }
+
+        Ok(body)
+    }
+
+    fn parse_print_statement(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'print'
+        let expr = self.parse_expr()?;
+        Ok(Stmt::Print(PrintStmt { expr, loc: self.get_loc() }))
+    }
+
+    fn parse_return_statement(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'return'
+        let value = if self.eof() || Self::is_stmt_end(self.at().kind) {
+            None
+        } else {
+            Some(self.parse_expr()?)
+        };
+
+        Ok(Stmt::Return(ReturnStmt { value, loc: self.get_loc() }))
+    }
+
+    // Tokens that implicitly end a statement even without a newline in
+    // between, since this parser lets statements abut without a separator.
+    // Same resync set `synchronize` looks for, plus the block/branch
+    // delimiters `}`/`else` that also close off a bare `return` with no value.
+    fn is_stmt_end(kind: TokenKind) -> bool {
+        matches!(
+            kind,
+            TokenKind::NewLine
+                | TokenKind::CloseBrace
+                | TokenKind::Else
+                | TokenKind::Struct
+                | TokenKind::Fn
+                | TokenKind::Var
+                | TokenKind::Const
+                | TokenKind::For
+                | TokenKind::If
+                | TokenKind::While
+                | TokenKind::Print
+                | TokenKind::Return
+        )
+    }
+
+    fn parse_fn_declaration(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'fn'
+        self.expect(TokenKind::Identifier)?;
+        let name = self.prev().value.clone();
+
+        self.expect(TokenKind::OpenParen)?;
+        let open_loc = Loc::new(self.start_loc, self.prev().loc.start);
+        let mut params = vec![];
+
+        if !self.is_at(TokenKind::CloseParen) {
+            loop {
+                self.expect(TokenKind::Identifier)?;
+                params.push(self.prev().value.clone());
+
+                if !self.is_at(TokenKind::Comma) {
+                    break
+                }
+                self.eat()?;
+            }
+        }
+
+        self.expect_or_resync(
+            TokenKind::CloseParen,
+            "Missing closing ')' for this parameter list".into(),
+            ParseContext::ParamList { open_loc },
+        )?;
+
+        let body = match self.parse_block()? {
+            Stmt::Block(b) => b.stmts,
+            _ => unreachable!("parse_block always returns Stmt::Block"),
+        };
+
+        Ok(Stmt::Fn(FnStmt { name, params, body, loc: self.get_loc() }))
+    }
+
+    fn parse_struct_declaration(&mut self) -> Result<Stmt, ArcResult> {
+        self.eat()?; // consumes 'struct'
+        self.expect(TokenKind::Identifier)?;
+        let name = self.prev().value.clone();
+
+        self.expect(TokenKind::OpenBrace)?;
+        let open_loc = Loc::new(self.start_loc, self.prev().loc.start);
+        let mut methods = vec![];
+
+        loop {
+            while !self.eof() && self.is_at(TokenKind::NewLine) {
+                self.current += 1;
+            }
+
+            if self.eof() || self.is_at(TokenKind::CloseBrace) {
+                break
+            }
+
+            if !self.is_at(TokenKind::Fn) {
+                return Err(self.trigger_error(
+                    "Expected method declaration or '}'".into(),
+                    true,
+                    Some(ParseContext::StructBody { open_loc }),
+                ))
+            }
+
+            match self.parse_fn_declaration()? {
+                Stmt::Fn(f) => methods.push(f),
+                _ => unreachable!("parse_fn_declaration always returns Stmt::Fn"),
+            }
+        }
+
+        self.expect_or_resync(
+            TokenKind::CloseBrace,
+            "Missing closing '}' for this struct".into(),
+            ParseContext::StructBody { open_loc },
+        )?;
+
+        Ok(Stmt::Struct(StructStmt { name, methods, loc: self.get_loc() }))
+    }
+
     fn parse_expr(&mut self) -> Result<Expr, ArcResult> {
-        self.parse_equality()
+        self.parse_assignment()
     }
 
-    fn parse_equality(&mut self) -> Result<Expr, ArcResult> {
-        let mut expr = self.parse_comparison()?;
+    fn parse_assignment(&mut self) -> Result<Expr, ArcResult> {
+        let expr = self.parse_or()?;
 
-        while self.is_at(TokenKind::EqualEqual) || self.is_at(TokenKind::BangEqual) {
-            let operator = self.eat()?.value.clone();
-            let right = self.parse_comparison()?;
-            expr = Expr::Binary(BinaryExpr {
-                left: Box::new(expr),
-                operator,
-                right: Box::new(right),
+        if self.is_at(TokenKind::Equal) {
+            self.eat()?;
+            let value = self.parse_assignment()?;
+
+            let name = match expr {
+                Expr::Identifier(id) => id.name,
+                _ => return Err(self.trigger_error("Invalid assignment target".into(), false, None)),
+            };
+
+            return Ok(Expr::Assign(AssignExpr {
+                name,
+                value: Box::new(value),
                 loc: self.get_loc(),
-            });
+                resolved_depth: None,
+            }))
         }
 
         Ok(expr)
     }
 
-    fn parse_comparison(&mut self) -> Result<Expr, ArcResult> {
-        let mut expr = self.parse_term()?;
+    fn parse_or(&mut self) -> Result<Expr, ArcResult> {
+        let mut expr = self.parse_and()?;
 
-        while self.is_at(TokenKind::Less)
-                || self.is_at(TokenKind::LessEqual)
-                || self.is_at(TokenKind::Greater)
-                || self.is_at(TokenKind::GreaterEqual)
-        {
+        while self.is_at(TokenKind::Or) {
             let operator = self.eat()?.value.clone();
-            let right = self.parse_term()?;
-            expr = Expr::Binary(BinaryExpr {
+            let right = self.parse_and()?;
+            expr = Expr::Logical(LogicalExpr {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
@@ -84,13 +421,13 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
-    fn parse_term(&mut self) -> Result<Expr, ArcResult> {
-        let mut expr = self.parse_factor()?;
+    fn parse_and(&mut self) -> Result<Expr, ArcResult> {
+        let mut expr = self.parse_expr_bp(0)?;
 
-        while self.is_at(TokenKind::Minus) || self.is_at(TokenKind::Plus) {
+        while self.is_at(TokenKind::And) {
             let operator = self.eat()?.value.clone();
-            let right = self.parse_factor()?;
-            expr = Expr::Binary(BinaryExpr {
+            let right = self.parse_expr_bp(0)?;
+            expr = Expr::Logical(LogicalExpr {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
@@ -101,12 +438,50 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
-    fn parse_factor(&mut self) -> Result<Expr, ArcResult> {
-        let mut expr = self.parse_unary()?;
+    // Binary operators' left binding power, looked up from a single table
+    // instead of one precedence-ladder function per level. Adding an
+    // operator, or changing its associativity, is now a single row edit.
+    fn binding_power(kind: TokenKind) -> Option<(u8, Assoc)> {
+        match kind {
+            TokenKind::EqualEqual | TokenKind::BangEqual => Some((10, Assoc::Left)),
+            TokenKind::Less | TokenKind::LessEqual | TokenKind::Greater | TokenKind::GreaterEqual => {
+                Some((20, Assoc::Left))
+            }
+            TokenKind::Plus | TokenKind::Minus => Some((30, Assoc::Left)),
+            TokenKind::Star | TokenKind::Slash => Some((40, Assoc::Left)),
+            _ => None,
+        }
+    }
+
+    // Binding power given to prefix `!`/`-`, above every binary operator so
+    // a unary always binds tighter than whatever follows it.
+    const PREFIX_BP: u8 = 50;
+
+    fn parse_expr_bp(&mut self, min_bp: u8) -> Result<Expr, ArcResult> {
+        let mut expr = if self.is_at(TokenKind::Bang) || self.is_at(TokenKind::Minus) {
+            let operator = self.eat()?.value.clone();
+            let right = self.parse_expr_bp(Self::PREFIX_BP)?;
+
+            Expr::Unary(UnaryExpr { operator, right: Box::new(right), loc: self.get_loc() })
+        } else {
+            self.parse_call()?
+        };
+
+        loop {
+            let Some((bp, assoc)) = Self::binding_power(self.at().kind) else { break };
+            if bp < min_bp { break }
 
-        while self.is_at(TokenKind::Star) || self.is_at(TokenKind::Slash) {
             let operator = self.eat()?.value.clone();
-            let right = self.parse_unary()?;
+            let next_min_bp = match assoc {
+                Assoc::Left => bp + 1,
+                Assoc::Right => bp,
+            };
+            // Propagate whatever error the right-hand side produced instead
+            // of replacing it with a generic one: deeper parses (an unclosed
+            // grouping, a too-long argument list, ...) already carry a more
+            // precise, correctly-located message than we could rebuild here.
+            let right = self.parse_expr_bp(next_min_bp)?;
+
             expr = Expr::Binary(BinaryExpr {
                 left: Box::new(expr),
                 operator,
@@ -118,19 +493,56 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
-    fn parse_unary(&mut self) -> Result<Expr, ArcResult> {
-        if self.is_at(TokenKind::Bang) || self.is_at(TokenKind::Minus) {
-            let operator = self.eat()?.value.clone();
-            let right = self.parse_primary()?;
+    fn parse_call(&mut self) -> Result<Expr, ArcResult> {
+        let mut expr = self.parse_primary()?;
 
-            return Ok(Expr::Unary(UnaryExpr {
-                operator,
-                right: Box::new(right),
-                loc: self.get_loc(),
-            }))
+        loop {
+            if self.is_at(TokenKind::OpenParen) {
+                expr = self.finish_call(expr)?;
+            } else if self.is_at(TokenKind::Dot) {
+                self.eat()?;
+                self.expect(TokenKind::Identifier)?;
+                let name = self.prev().value.clone();
+
+                expr = Expr::Get(GetExpr { object: Box::new(expr), name, loc: self.get_loc() });
+            } else {
+                break
+            }
         }
 
-        self.parse_primary()
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Expr) -> Result<Expr, ArcResult> {
+        let paren_start = self.at().loc.start;
+        self.eat()?; // consumes '('
+        let open_loc = Loc::new(self.start_loc, self.prev().loc.start);
+
+        let mut args = vec![];
+
+        if !self.is_at(TokenKind::CloseParen) {
+            loop {
+                if args.len() >= Self::MAX_ARGS {
+                    return Err(self.trigger_error(
+                        format!("Can't have more than {} arguments", Self::MAX_ARGS),
+                        true,
+                        Some(ParseContext::CallArgs { open_loc }),
+                    ))
+                }
+
+                args.push(self.parse_expr()?);
+
+                if !self.is_at(TokenKind::Comma) {
+                    break
+                }
+                self.eat()?;
+            }
+        }
+
+        self.expect(TokenKind::CloseParen)?;
+        let paren_loc = Loc::new(paren_start, self.prev().loc.start);
+
+        Ok(Expr::Call(CallExpr { callee: Box::new(callee), args, paren_loc, loc: self.get_loc() }))
     }
 
     fn parse_primary(&mut self) -> Result<Expr, ArcResult> {
@@ -142,13 +554,21 @@ impl<'a> Parser<'a> {
                 IdentifierExpr {
                     name: self.prev().value.clone(),
                     loc: self.get_loc(),
+                    resolved_depth: None,
                 })
             ),
             TokenKind::Int => self.parse_int_literal(),
             TokenKind::Real => self.parse_real_literal(),
+            TokenKind::String => self.parse_string_literal(),
             TokenKind::OpenParen => self.parse_grouping(),
-            TokenKind::NewLine => { Err(self.trigger_error("Unexpected end of line".into(), false)) },
-            _ => Err(self.trigger_error(format!("Unknown token to parse: '{}'", self.prev()), true))
+            TokenKind::NewLine => {
+                Err(self.trigger_error("Unexpected end of line".into(), false, Some(ParseContext::Expression)))
+            },
+            _ => Err(self.trigger_error(
+                format!("Unknown token to parse: '{}'", self.prev()),
+                true,
+                Some(ParseContext::Expression),
+            ))
         }
     }
 
@@ -166,10 +586,95 @@ impl<'a> Parser<'a> {
         Ok(Expr::RealLiteral(RealLiteralExpr { value, loc: self.get_loc() }))
     }
 
+    fn parse_string_literal(&mut self) -> Result<Expr, ArcResult> {
+        let tk = self.prev();
+        let raw = tk.value.clone();
+        let tk_loc = tk.loc;
+        let has_escape = raw.contains('\\');
+
+        if !has_escape {
+            return Ok(Expr::StringLiteral(StringLiteralExpr { value: raw, has_escape, loc: self.get_loc() }))
+        }
+
+        let value = self.decode_escapes(&raw, tk_loc)?;
+
+        Ok(Expr::StringLiteral(StringLiteralExpr { value, has_escape, loc: self.get_loc() }))
+    }
+
+    // Decodes `\n`, `\t`, `\\`, `\"` and `\uXXXX` escapes, pointing the error
+    // at the offending character's own offset rather than the whole literal.
+    fn decode_escapes(&mut self, raw: &str, tk_loc: Loc) -> Result<EcoString, ArcResult> {
+        let chars: Vec<char> = raw.chars().collect();
+        let mut decoded = String::with_capacity(raw.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] != '\\' {
+                decoded.push(chars[i]);
+                i += 1;
+                continue
+            }
+
+            let offset_loc = Loc::new(tk_loc.start + i, tk_loc.start + i + 1);
+
+            let escaped = match chars.get(i + 1) {
+                Some('n') => { i += 2; '\n' }
+                Some('t') => { i += 2; '\t' }
+                Some('\\') => { i += 2; '\\' }
+                Some('"') => { i += 2; '"' }
+                Some('u') => {
+                    let hex: String = chars.iter().skip(i + 2).take(4).collect();
+                    if hex.len() != 4 {
+                        return Err(self.trigger_error_at(
+                            "Invalid '\\u' escape sequence, expected 4 hex digits".into(),
+                            offset_loc,
+                            true,
+                            None,
+                        ))
+                    }
+
+                    let Ok(code_point) = u32::from_str_radix(&hex, 16) else {
+                        return Err(self.trigger_error_at(
+                            "Invalid '\\u' escape sequence, expected 4 hex digits".into(),
+                            offset_loc,
+                            true,
+                            None,
+                        ))
+                    };
+
+                    match char::from_u32(code_point) {
+                        Some(c) => { i += 6; c }
+                        None => return Err(self.trigger_error_at(
+                            "Invalid '\\u' escape sequence, not a valid Unicode scalar value".into(),
+                            offset_loc,
+                            true,
+                            None,
+                        )),
+                    }
+                }
+                _ => return Err(self.trigger_error_at(
+                    "Unknown escape sequence in string literal".into(),
+                    offset_loc,
+                    true,
+                    None,
+                )),
+            };
+
+            decoded.push(escaped);
+        }
+
+        Ok(EcoString::from(decoded))
+    }
+
     fn parse_grouping(&mut self) -> Result<Expr, ArcResult> {
+        let open_loc = Loc::new(self.start_loc, self.prev().loc.start);
         let expr = self.parse_expr()?;
-        println!("\nGrouping end, we are at: {:?}\n", self.at());
-        self.expect(TokenKind::CloseParen)?;
+
+        self.expect_or_resync(
+            TokenKind::CloseParen,
+            "Missing closing ')' for this grouping".into(),
+            ParseContext::Grouping { open_loc },
+        )?;
 
         Ok(Expr::Grouping(GroupingExpr { expr: Box::new(expr), loc: self.get_loc() }))
     }
@@ -186,7 +691,7 @@ impl<'a> Parser<'a> {
         self.current += 1;
         Ok(self.prev())
     }
-    
+
     fn expect(&mut self, kind: TokenKind) -> Result<(), ArcResult> {
         let tk = self.eat()?;
 
@@ -194,7 +699,7 @@ impl<'a> Parser<'a> {
             true => Ok(()),
             false => {
                 let msg = format!("Expected token type '{:?}', found: {:?}", kind, tk.kind);
-                Err(self.trigger_error(msg, true))
+                Err(self.trigger_error(msg, true, None))
             }
         }
     }
@@ -206,30 +711,105 @@ impl<'a> Parser<'a> {
     fn prev(&self) -> &Token {
         self.tokens.get(self.current - 1).unwrap()
     }
-    
+
     fn eof(&self) -> bool {
         self.is_at(TokenKind::Eof)
     }
 
+    // Checkpoint the cursor so a speculative parse can be abandoned with
+    // `restore` if it turns out not to pan out (e.g. grouping recovery).
+    fn snapshot(&self) -> usize {
+        self.current
+    }
+
+    fn restore(&mut self, mark: usize) {
+        self.current = mark;
+    }
+
+    // Expects a closing delimiter ('}', ')', ...); if it's missing — whether
+    // because the wrong token sits there or because we hit EOF first — roll
+    // back so the offending token (if any) is handed to the context-aware
+    // resync of `ctx` instead of `expect`'s own generic synchronize, and
+    // raise our own located, context-aware error in its place. This also
+    // keeps an EOF from running straight into `eat`'s "out of bound" guard:
+    // `expect` would otherwise propagate that internal error untouched.
+    fn expect_or_resync(&mut self, kind: TokenKind, msg: String, ctx: ParseContext) -> Result<(), ArcResult> {
+        let mark = self.snapshot();
+        if self.expect(kind).is_err() {
+            self.restore(mark);
+            return Err(self.trigger_error(msg, true, Some(ctx)))
+        }
+        Ok(())
+    }
+
     // We dont have to activate the synchro each time, if the error occured
     // because we ate a '\n' that wasn't supposed to be here, we are already
     // past the error, we are on the new line. No need to synchronize
-    fn trigger_error(&mut self, msg: String, synchro: bool) -> ArcResult {
+    fn trigger_error(&mut self, msg: String, synchro: bool, ctx: Option<ParseContext>) -> ArcResult {
+        self.trigger_error_at(msg, self.get_loc(), synchro, ctx)
+    }
+
+    // Same as `trigger_error`, but for error sites (like string-escape
+    // decoding) that already know a more precise location than whatever
+    // `self.get_loc()` would report for the parser's current position.
+    fn trigger_error_at(&mut self, msg: String, loc: Loc, synchro: bool, ctx: Option<ParseContext>) -> ArcResult {
         if synchro {
-            self.synchronize();
+            self.synchronize(ctx);
         }
-        
-        ArcResult::parser_error(msg, self.get_loc())
+
+        let msg = match ctx {
+            Some(ctx) => format!("{msg}: {}", ctx.describe()),
+            None => msg,
+        };
+
+        ArcResult::parser_error(msg, loc)
     }
 
-    // TODO: For now, we are only looking for new line token as we
-    // don't have ';' to clearly know where the current statement stops.
-    // It would be great to have an argument to this function that let
-    // us know where we were when we got the error to know which corresponding
-    // token to look for.
+    // We are here in panic mode. `ctx` lets us pick a resync point suited to
+    // what we were parsing instead of always skipping to the next statement:
+    // for an unclosed grouping, an overflowing call's argument list, or a
+    // truncated parameter list we skip to the matching ')' (tracking nesting
+    // so an inner one's ')' isn't mistaken for ours), or stop at a newline
+    // outside any inner parens if one comes first, rather than blowing
+    // past it to the next keyword/newline, which would otherwise treat
+    // everything still inside as its own statement soup. A malformed struct
+    // body or an unterminated block gets the same treatment with '{'/'}'
+    // instead, so the whole construct is swallowed rather than spilling its
+    // contents out as top-level statements. We still don't track a real
+    // statement-boundary token (there's no ';'), so every other context
+    // falls back to the keyword/newline scan below. Actually reconstructing
+    // a node as if the missing token were there (rather than just resyncing
+    // past it) is intentionally out of scope here.
+    fn synchronize(&mut self, ctx: Option<ParseContext>) {
+        if let Some(
+            ParseContext::Grouping { .. } | ParseContext::CallArgs { .. } | ParseContext::ParamList { .. },
+        ) = ctx {
+            let mut depth = 0usize;
+            while !self.eof() {
+                match self.at().kind {
+                    TokenKind::OpenParen => { depth += 1; let _ = self.eat(); }
+                    TokenKind::CloseParen if depth == 0 => { let _ = self.eat(); return }
+                    TokenKind::CloseParen => { depth -= 1; let _ = self.eat(); }
+                    TokenKind::NewLine if depth == 0 => return,
+                    _ => { let _ = self.eat(); }
+                }
+            }
+            return
+        }
+
+        if let Some(ParseContext::StructBody { .. } | ParseContext::Block { .. }) = ctx {
+            let mut depth = 0usize;
+            while !self.eof() {
+                match self.at().kind {
+                    TokenKind::OpenBrace => { depth += 1; let _ = self.eat(); }
+                    TokenKind::CloseBrace if depth == 0 => { let _ = self.eat(); return }
+                    TokenKind::CloseBrace => { depth -= 1; let _ = self.eat(); }
+                    _ => { let _ = self.eat(); }
+                }
+            }
+            return
+        }
 
-    // We are here in panic mode
-    fn synchronize(&mut self) {
         // We parse potential other errors in statements
         while !self.eof() {
             match self.at().kind {
@@ -260,6 +840,7 @@ mod tests {
     use crate::lexer::{Lexer, Loc};
     use super::Parser;
     use crate::expr::*;
+    use crate::stmt::*;
 
     #[test]
     fn parse_primary() {
@@ -272,35 +853,52 @@ mod tests {
         assert_eq!(
             nodes,
             &vec![
-                Expr::IntLiteral(IntLiteralExpr {
-                    value: 12,
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::IntLiteral(IntLiteralExpr {
+                        value: 12,
+                        loc: Loc::new(0, 2),
+                    }),
                     loc: Loc::new(0, 2),
                 }),
-                Expr::RealLiteral(RealLiteralExpr {
-                    value: 24.,
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::RealLiteral(RealLiteralExpr {
+                        value: 24.,
+                        loc: Loc::new(4, 6),
+                    }),
                     loc: Loc::new(4, 6),
                 }),
-                Expr::RealLiteral(RealLiteralExpr {
-                    value: 54.678,
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::RealLiteral(RealLiteralExpr {
+                        value: 54.678,
+                        loc: Loc::new(8, 13),
+                    }),
                     loc: Loc::new(8, 13),
                 }),
-                Expr::Grouping(GroupingExpr {
-                    expr: Box::new(Expr::Identifier(IdentifierExpr {
-                        name: EcoString::from("true"),
-                        loc: Loc::new(16, 19),
-                    })),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Grouping(GroupingExpr {
+                        expr: Box::new(Expr::Identifier(IdentifierExpr {
+                            name: EcoString::from("true"),
+                            loc: Loc::new(16, 19),
+                            resolved_depth: None,
+                        })),
+                        loc: Loc::new(15, 20),
+                    }),
                     loc: Loc::new(15, 20),
                 }),
-                Expr::Grouping(GroupingExpr {
-                    expr: Box::new(
-                        Expr::Grouping(GroupingExpr {
-                            expr: Box::new(Expr::Identifier(IdentifierExpr {
-                                name: EcoString::from("null"),
-                                loc: Loc::new(25, 28),
-                            })),
-                            loc: Loc::new(24, 30)
-                        })
-                    ),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Grouping(GroupingExpr {
+                        expr: Box::new(
+                            Expr::Grouping(GroupingExpr {
+                                expr: Box::new(Expr::Identifier(IdentifierExpr {
+                                    name: EcoString::from("null"),
+                                    loc: Loc::new(25, 28),
+                                    resolved_depth: None,
+                                })),
+                                loc: Loc::new(24, 30)
+                            })
+                        ),
+                        loc: Loc::new(22, 31),
+                    }),
                     loc: Loc::new(22, 31),
                 }),
             ]
@@ -318,39 +916,448 @@ mod tests {
         assert_eq!(
             nodes,
             &vec![
-                Expr::Unary(UnaryExpr {
-                    operator: EcoString::from("-"),
-                    right: Box::new(Expr::IntLiteral(IntLiteralExpr {
-                        value: 12,
-                        loc: Loc::new(0, 1),
-                    })),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Unary(UnaryExpr {
+                        operator: EcoString::from("-"),
+                        right: Box::new(Expr::IntLiteral(IntLiteralExpr {
+                            value: 12,
+                            loc: Loc::new(0, 1),
+                        })),
+                        loc: Loc::new(0, 2),
+                    }),
                     loc: Loc::new(0, 2),
                 }),
-                Expr::Unary(UnaryExpr {
-                    operator: EcoString::from("-"),
-                    right: Box::new(Expr::RealLiteral(RealLiteralExpr {
-                        value: 24.,
-                        loc: Loc::new(5, 7),
-                    })),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Unary(UnaryExpr {
+                        operator: EcoString::from("-"),
+                        right: Box::new(Expr::RealLiteral(RealLiteralExpr {
+                            value: 24.,
+                            loc: Loc::new(5, 7),
+                        })),
+                        loc: Loc::new(4, 7),
+                    }),
                     loc: Loc::new(4, 7),
                 }),
-                Expr::Unary(UnaryExpr {
-                    operator: EcoString::from("-"),
-                    right: Box::new(Expr::RealLiteral(RealLiteralExpr {
-                        value: 54.67,
-                        loc: Loc::new(10, 14),
-                    })),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Unary(UnaryExpr {
+                        operator: EcoString::from("-"),
+                        right: Box::new(Expr::RealLiteral(RealLiteralExpr {
+                            value: 54.67,
+                            loc: Loc::new(10, 14),
+                        })),
+                        loc: Loc::new(9, 14),
+                    }),
                     loc: Loc::new(9, 14),
                 }),
-                Expr::Unary(UnaryExpr {
-                    operator: EcoString::from("!"),
-                    right: Box::new(Expr::Identifier(IdentifierExpr {
-                        name: EcoString::from("true"),
-                        loc: Loc::new(17, 20),
-                    })),
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Unary(UnaryExpr {
+                        operator: EcoString::from("!"),
+                        right: Box::new(Expr::Identifier(IdentifierExpr {
+                            name: EcoString::from("true"),
+                            loc: Loc::new(17, 20),
+                            resolved_depth: None,
+                        })),
+                        loc: Loc::new(16, 20),
+                    }),
                     loc: Loc::new(16, 20),
                 }),
             ]
         )
     }
+
+    #[test]
+    fn parse_double_unary() {
+        let code: String = "--12".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(
+            nodes,
+            &vec![
+                Stmt::Expr(ExprStmt {
+                    expr: Expr::Unary(UnaryExpr {
+                        operator: EcoString::from("-"),
+                        right: Box::new(Expr::Unary(UnaryExpr {
+                            operator: EcoString::from("-"),
+                            right: Box::new(Expr::IntLiteral(IntLiteralExpr {
+                                value: 12,
+                                loc: Loc::new(2, 4),
+                            })),
+                            loc: Loc::new(1, 4),
+                        })),
+                        loc: Loc::new(0, 4),
+                    }),
+                    loc: Loc::new(0, 4),
+                }),
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_var_and_const_declaration() {
+        let code: String = "var a = 1\nconst b = 2".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 2);
+
+        let Stmt::Var(var) = &nodes[0] else { panic!("expected a var statement") };
+        assert_eq!(var.name, EcoString::from("a"));
+        assert!(!var.is_const);
+        assert!(matches!(&var.initializer, Some(Expr::IntLiteral(IntLiteralExpr { value: 1, .. }))));
+
+        let Stmt::Var(konst) = &nodes[1] else { panic!("expected a const statement") };
+        assert_eq!(konst.name, EcoString::from("b"));
+        assert!(konst.is_const);
+        assert!(matches!(&konst.initializer, Some(Expr::IntLiteral(IntLiteralExpr { value: 2, .. }))));
+    }
+
+    #[test]
+    fn parse_block_and_if_else() {
+        let code: String = "if a { print 1 } else { print 2 }".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::If(if_stmt) = &nodes[0] else { panic!("expected an if statement") };
+        assert!(matches!(&if_stmt.condition, Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "a"));
+
+        let Stmt::Block(then_block) = if_stmt.then_branch.as_ref() else { panic!("expected a block") };
+        assert_eq!(then_block.stmts.len(), 1);
+        assert!(matches!(
+            &then_block.stmts[0],
+            Stmt::Print(PrintStmt { expr: Expr::IntLiteral(IntLiteralExpr { value: 1, .. }), .. })
+        ));
+
+        let else_branch = if_stmt.else_branch.as_ref().expect("expected an else branch");
+        let Stmt::Block(else_block) = else_branch.as_ref() else { panic!("expected a block") };
+        assert_eq!(else_block.stmts.len(), 1);
+        assert!(matches!(
+            &else_block.stmts[0],
+            Stmt::Print(PrintStmt { expr: Expr::IntLiteral(IntLiteralExpr { value: 2, .. }), .. })
+        ));
+    }
+
+    // Regression test: `return` followed by a statement-boundary token (here
+    // `else`) with no newline in between must parse as a value-less return,
+    // not try to parse `else` itself as an expression.
+    #[test]
+    fn parse_return_statement_without_value_before_else() {
+        let code: String = "if a return else print 1".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::If(if_stmt) = &nodes[0] else { panic!("expected an if statement") };
+        assert!(matches!(if_stmt.then_branch.as_ref(), Stmt::Return(ReturnStmt { value: None, .. })));
+
+        let else_branch = if_stmt.else_branch.as_ref().expect("expected an else branch");
+        assert!(matches!(else_branch.as_ref(), Stmt::Print(_)));
+    }
+
+    #[test]
+    fn parse_while_and_for() {
+        let code: String = "while a { print 1 }\nfor (var i = 0; i; i) print i".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 2);
+
+        let Stmt::While(while_stmt) = &nodes[0] else { panic!("expected a while statement") };
+        assert!(matches!(&while_stmt.condition, Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "a"));
+        let Stmt::Block(body) = while_stmt.body.as_ref() else { panic!("expected a block body") };
+        assert_eq!(body.stmts.len(), 1);
+
+        // `for (init; cond; incr) body` desugars into
+        // `{ init; while (cond) { body; incr } }`.
+        let Stmt::Block(desugared) = &nodes[1] else { panic!("expected for-loop to desugar into a block") };
+        assert_eq!(desugared.stmts.len(), 2);
+        assert!(matches!(
+            &desugared.stmts[0],
+            Stmt::Var(VarStmt { name, is_const: false, .. }) if name.as_str() == "i"
+        ));
+
+        let Stmt::While(loop_stmt) = &desugared.stmts[1] else { panic!("expected the desugared while loop") };
+        assert!(matches!(&loop_stmt.condition, Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "i"));
+
+        let Stmt::Block(loop_body) = loop_stmt.body.as_ref() else { panic!("expected loop body wrapped with the increment") };
+        assert_eq!(loop_body.stmts.len(), 2);
+        assert!(matches!(&loop_body.stmts[0], Stmt::Print(_)));
+        assert!(matches!(
+            &loop_body.stmts[1],
+            Stmt::Expr(ExprStmt { expr: Expr::Identifier(IdentifierExpr { name, .. }), .. }) if name.as_str() == "i"
+        ));
+    }
+
+    #[test]
+    fn parse_print_and_return() {
+        let code: String = "print 1\nfn f() { return }\nfn g() { return 1 }".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 3);
+        assert!(matches!(
+            &nodes[0],
+            Stmt::Print(PrintStmt { expr: Expr::IntLiteral(IntLiteralExpr { value: 1, .. }), .. })
+        ));
+
+        let Stmt::Fn(f) = &nodes[1] else { panic!("expected fn declaration") };
+        assert_eq!(f.body.len(), 1);
+        assert!(matches!(&f.body[0], Stmt::Return(ReturnStmt { value: None, .. })));
+
+        let Stmt::Fn(g) = &nodes[2] else { panic!("expected fn declaration") };
+        assert_eq!(g.body.len(), 1);
+        assert!(matches!(
+            &g.body[0],
+            Stmt::Return(ReturnStmt { value: Some(Expr::IntLiteral(IntLiteralExpr { value: 1, .. })), .. })
+        ));
+    }
+
+    #[test]
+    fn parse_fn_and_struct_declaration() {
+        let code: String =
+            "fn add(a, b) { return a }\nstruct Point { fn new(x, y) { return x } fn reset() { return 0 } }".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 2);
+
+        let Stmt::Fn(add) = &nodes[0] else { panic!("expected fn declaration") };
+        assert_eq!(add.name, EcoString::from("add"));
+        assert_eq!(add.params, vec![EcoString::from("a"), EcoString::from("b")]);
+        assert_eq!(add.body.len(), 1);
+
+        let Stmt::Struct(point) = &nodes[1] else { panic!("expected struct declaration") };
+        assert_eq!(point.name, EcoString::from("Point"));
+        assert_eq!(point.methods.len(), 2);
+        assert_eq!(point.methods[0].name, EcoString::from("new"));
+        assert_eq!(point.methods[0].params, vec![EcoString::from("x"), EcoString::from("y")]);
+        assert_eq!(point.methods[1].name, EcoString::from("reset"));
+    }
+
+    // Regression test: a non-`fn` token inside a struct body must produce a
+    // single clean error instead of `parse_fn_declaration` silently eating it
+    // as if it were `fn` and cascading into spurious follow-up errors.
+    #[test]
+    fn parse_struct_declaration_rejects_non_method_members() {
+        let code: String = "struct Point { var x }".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // A block left open at EOF must report a single, located parser error,
+    // not propagate `expect`'s internal "out of bound" guard as an ICE.
+    #[test]
+    fn parse_unterminated_block_reports_one_error() {
+        let code: String = "if a { print 1".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_assignment() {
+        let code: String = "a = b".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::Expr(ExprStmt { expr, .. }) = &nodes[0] else { panic!("expected an expression statement") };
+        let Expr::Assign(assign) = expr else { panic!("expected an assignment") };
+        assert_eq!(assign.name, EcoString::from("a"));
+        assert!(matches!(assign.value.as_ref(), Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "b"));
+    }
+
+    #[test]
+    fn parse_invalid_assignment_target() {
+        let code: String = "1 = 2".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // `and` binds tighter than `or`, so `a and b or c` must parse as
+    // `(a and b) or c`, not `a and (b or c)`.
+    #[test]
+    fn parse_logical_precedence() {
+        let code: String = "a and b or c".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::Expr(ExprStmt { expr, .. }) = &nodes[0] else { panic!("expected an expression statement") };
+        let Expr::Logical(or_expr) = expr else { panic!("expected a logical expression") };
+        assert_eq!(or_expr.operator, EcoString::from("or"));
+        assert!(matches!(or_expr.right.as_ref(), Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "c"));
+
+        let Expr::Logical(and_expr) = or_expr.left.as_ref() else { panic!("expected `a and b` to be the left operand") };
+        assert_eq!(and_expr.operator, EcoString::from("and"));
+        assert!(matches!(and_expr.left.as_ref(), Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "a"));
+        assert!(matches!(and_expr.right.as_ref(), Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "b"));
+    }
+
+    #[test]
+    fn parse_call_and_get_chain() {
+        let code: String = "f()().c".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::Expr(ExprStmt { expr, .. }) = &nodes[0] else { panic!("expected an expression statement") };
+        let Expr::Get(get) = expr else { panic!("expected a get expression") };
+        assert_eq!(get.name, EcoString::from("c"));
+
+        let Expr::Call(outer_call) = get.object.as_ref() else { panic!("expected a call on the result of the first call") };
+        assert!(outer_call.args.is_empty());
+
+        let Expr::Call(inner_call) = outer_call.callee.as_ref() else { panic!("expected the callee to be a call itself") };
+        assert!(inner_call.args.is_empty());
+        assert!(matches!(inner_call.callee.as_ref(), Expr::Identifier(IdentifierExpr { name, .. }) if name.as_str() == "f"));
+    }
+
+    // `finish_call` must reject a call with more than `MAX_ARGS` arguments
+    // instead of accepting it unbounded.
+    #[test]
+    fn parse_call_argument_limit() {
+        let args = (0..=Parser::MAX_ARGS).map(|i| i.to_string()).collect::<Vec<_>>().join(", ");
+        let code = format!("f({args})");
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // An unclosed grouping must report a single, precisely-located error,
+    // not a cascade of generic ones from every enclosing construct that
+    // also failed to find its operand.
+    #[test]
+    fn parse_unclosed_grouping_reports_one_error() {
+        let code: String = "1 + (2 + 3".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // The context-aware resync for a missing ')' should only cost us the
+    // statement it broke in, leaving the next statement free to parse
+    // cleanly instead of being swallowed by the recovery scan.
+    #[test]
+    fn parse_unclosed_grouping_recovers_for_next_statement() {
+        let code: String = "(1 + 2\nprint 3".into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_string_literal_escapes() {
+        let code: String = r#""a\nb\t\"\\A""#.into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::Expr(ExprStmt { expr, .. }) = &nodes[0] else { panic!("expected an expression statement") };
+        let Expr::StringLiteral(lit) = expr else { panic!("expected a string literal") };
+        assert!(lit.has_escape);
+        assert_eq!(lit.value, EcoString::from("a\nb\t\"\\A"));
+    }
+
+    #[test]
+    fn parse_string_literal_unicode_escape() {
+        let code: String = r#""\u0041""#.into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let nodes = parser.parse().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+
+        let Stmt::Expr(ExprStmt { expr, .. }) = &nodes[0] else { panic!("expected an expression statement") };
+        let Expr::StringLiteral(lit) = expr else { panic!("expected a string literal") };
+        assert!(lit.has_escape);
+        assert_eq!(lit.value, EcoString::from("A"));
+    }
+
+    #[test]
+    fn parse_string_literal_unknown_escape_is_an_error() {
+        let code: String = r#""bad\x""#.into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // `\u` must be followed by exactly 4 hex digits; `\u41` is one short and
+    // must not silently decode to `'A'` by taking whatever digits follow.
+    #[test]
+    fn parse_string_literal_short_unicode_escape_is_an_error() {
+        let code: String = r#""bad\u41""#.into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
+
+    // Correct length, but not valid hex digits: a distinct failure mode from
+    // the short-escape case above, and must still be reported (not panic).
+    #[test]
+    fn parse_string_literal_non_hex_unicode_escape_is_an_error() {
+        let code: String = r#""bad\uZZZZ""#.into();
+        let mut lexer = Lexer::new(code.as_str());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let errors = parser.parse().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+    }
 }
\ No newline at end of file
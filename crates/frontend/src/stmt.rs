@@ -0,0 +1,79 @@
+use ecow::EcoString;
+
+use crate::expr::Expr;
+use crate::lexer::Loc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stmt {
+    Expr(ExprStmt),
+    Var(VarStmt),
+    Block(BlockStmt),
+    If(IfStmt),
+    While(WhileStmt),
+    Print(PrintStmt),
+    Return(ReturnStmt),
+    Fn(FnStmt),
+    Struct(StructStmt),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExprStmt {
+    pub expr: Expr,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VarStmt {
+    pub name: EcoString,
+    pub initializer: Option<Expr>,
+    pub is_const: bool,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockStmt {
+    pub stmts: Vec<Stmt>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfStmt {
+    pub condition: Expr,
+    pub then_branch: Box<Stmt>,
+    pub else_branch: Option<Box<Stmt>>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhileStmt {
+    pub condition: Expr,
+    pub body: Box<Stmt>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintStmt {
+    pub expr: Expr,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnStmt {
+    pub value: Option<Expr>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FnStmt {
+    pub name: EcoString,
+    pub params: Vec<EcoString>,
+    pub body: Vec<Stmt>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructStmt {
+    pub name: EcoString,
+    pub methods: Vec<FnStmt>,
+    pub loc: Loc,
+}